@@ -115,6 +115,22 @@ impl<B: AsMut<Vec<u8>>> InnerArrayBuilder<B> {
         InnerArrayBuilder::try_new(bytes, element_count)
     }
 
+    #[inline]
+    fn push_packed_array(
+        &mut self,
+        element_data_type: DataType,
+        element_count: u16,
+    ) -> BuildResult<InnerPackedArrayBuilder<&mut Vec<u8>>> {
+        let f = |bytes: &mut Vec<u8>, offset: u32, value_entry_pos: usize| {
+            bytes.write_offset(offset, value_entry_pos + DATA_TYPE_SIZE);
+            Ok(())
+        };
+        self.push_value(DataType::PackedArray, f)?;
+
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        InnerPackedArrayBuilder::try_new(bytes, element_data_type, element_count)
+    }
+
     #[inline]
     fn push_string(&mut self, value: &str) -> BuildResult<()> {
         let size = MAX_DATA_LENGTH_SIZE + value.len();
@@ -129,93 +145,1032 @@ impl<B: AsMut<Vec<u8>>> InnerArrayBuilder<B> {
 
     #[inline]
     fn push_number(&mut self, value: Number) -> BuildResult<()> {
-        let size = MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE;
-        let f = |bytes: &mut Vec<u8>, offset: u32, value_entry_pos: usize| {
-            bytes.write_offset(offset, value_entry_pos + DATA_TYPE_SIZE);
-            bytes.try_reserve(size)?;
-            bytes.push_number(value);
-            Ok(())
-        };
-        self.push_value(DataType::Number, f)
+        let size = MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE;
+        let f = |bytes: &mut Vec<u8>, offset: u32, value_entry_pos: usize| {
+            bytes.write_offset(offset, value_entry_pos + DATA_TYPE_SIZE);
+            bytes.try_reserve(size)?;
+            bytes.push_number(value);
+            Ok(())
+        };
+        self.push_value(DataType::Number, f)
+    }
+
+    #[inline]
+    fn push_bool(&mut self, value: bool) -> BuildResult<()> {
+        // bool can be inlined
+        let f = |bytes: &mut Vec<u8>, _offset: u32, value_entry_pos: usize| {
+            bytes.write_offset(value as u32, value_entry_pos + DATA_TYPE_SIZE);
+            Ok(())
+        };
+        self.push_value(DataType::Bool, f)
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> BuildResult<()> {
+        // null can be inlined
+        self.push_value(DataType::Null, |_, _, _| Ok(()))
+    }
+
+    /// Pushes a slice of numbers in a single reservation.
+    #[inline]
+    fn push_number_slice(&mut self, values: &[Number]) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let size = values.len() * (MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE);
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes.try_reserve(size)?;
+
+        for &value in values {
+            bytes.write_data_type_by_pos(DataType::Number, self.value_entry_pos);
+            let offset = bytes.len() - self.start_pos;
+            bytes.write_offset(offset as u32, self.value_entry_pos + DATA_TYPE_SIZE);
+            bytes.push_number(value);
+            self.value_entry_pos += VALUE_ENTRY_SIZE;
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of strings in a single reservation.
+    #[inline]
+    fn push_string_iter<'a, I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        let size: usize = iter.clone().map(|value| MAX_DATA_LENGTH_SIZE + value.len()).sum();
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes.try_reserve(size)?;
+
+        for value in iter {
+            bytes.write_data_type_by_pos(DataType::String, self.value_entry_pos);
+            let offset = bytes.len() - self.start_pos;
+            bytes.write_offset(offset as u32, self.value_entry_pos + DATA_TYPE_SIZE);
+            bytes.push_string(value)?;
+            self.value_entry_pos += VALUE_ENTRY_SIZE;
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes a slice of bools. Bools are inlined, so no extra reservation is needed.
+    #[inline]
+    fn push_bool_slice(&mut self, values: &[bool]) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        for &value in values {
+            bytes.write_data_type_by_pos(DataType::Bool, self.value_entry_pos);
+            bytes.write_offset(value as u32, self.value_entry_pos + DATA_TYPE_SIZE);
+            self.value_entry_pos += VALUE_ENTRY_SIZE;
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of optional numbers in a single reservation, translating `None` into
+    /// an inlined `Null` entry and `Some` into the corresponding `Number` entry.
+    #[inline]
+    fn push_number_options<I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = Option<Number>>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        let size: usize = iter
+            .clone()
+            .map(|value| if value.is_some() { MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE } else { 0 })
+            .sum();
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes.try_reserve(size)?;
+
+        for value in iter {
+            match value {
+                Some(value) => {
+                    bytes.write_data_type_by_pos(DataType::Number, self.value_entry_pos);
+                    let offset = bytes.len() - self.start_pos;
+                    bytes.write_offset(offset as u32, self.value_entry_pos + DATA_TYPE_SIZE);
+                    bytes.push_number(value);
+                }
+                None => bytes.write_data_type_by_pos(DataType::Null, self.value_entry_pos),
+            }
+            self.value_entry_pos += VALUE_ENTRY_SIZE;
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of optional strings in a single reservation, translating `None` into
+    /// an inlined `Null` entry and `Some` into the corresponding `String` entry.
+    #[inline]
+    fn push_string_options<'a, I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = Option<&'a str>>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        let size: usize = iter
+            .clone()
+            .map(|value| value.map_or(0, |value| MAX_DATA_LENGTH_SIZE + value.len()))
+            .sum();
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes.try_reserve(size)?;
+
+        for value in iter {
+            match value {
+                Some(value) => {
+                    bytes.write_data_type_by_pos(DataType::String, self.value_entry_pos);
+                    let offset = bytes.len() - self.start_pos;
+                    bytes.write_offset(offset as u32, self.value_entry_pos + DATA_TYPE_SIZE);
+                    bytes.push_string(value)?;
+                }
+                None => bytes.write_data_type_by_pos(DataType::Null, self.value_entry_pos),
+            }
+            self.value_entry_pos += VALUE_ENTRY_SIZE;
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// An `InnerArrayBuilder` variant that does not require the element count to be known up front.
+///
+/// Rather than reserving a fixed value-entry region ahead of time, this builder accumulates
+/// value-entries and out-of-line value payloads in their own growable buffers and only lays
+/// them out into their final positions, with the element count backfilled, at [`finish`](Self::finish).
+/// This trades the fast-path's single upfront reservation for the ability to append from an
+/// iterator of unknown length without ever failing on a count mismatch.
+pub(crate) struct InnerGrowableArrayBuilder<B: AsMut<Vec<u8>>> {
+    bytes_wrapper: BytesWrapper<B>,
+    start_pos: usize,
+    entries: Vec<u8>,
+    payload: Vec<u8>,
+    // Each entry is the position of a value-entry's 4-byte offset field within `entries`,
+    // paired with the `payload`-relative offset written there provisionally; once `payload`'s
+    // final base (the element-count field plus the entries region's final size) is known, it's
+    // added back in at `finish`.
+    offset_fixups: Vec<(usize, u32)>,
+    value_count: u16,
+    depth: usize,
+    bytes_init_len: usize,
+}
+
+impl<B: AsMut<Vec<u8>>> InnerGrowableArrayBuilder<B> {
+    #[inline]
+    pub(crate) fn try_new(bytes: B) -> BuildResult<Self> {
+        let mut bytes_wrapper = BytesWrapper::new(bytes);
+        let bytes = bytes_wrapper.bytes.as_mut();
+        let bytes_init_len = bytes.len();
+
+        bytes.try_reserve(DATA_TYPE_SIZE + ARRAY_SIZE + ELEMENT_COUNT_SIZE)?;
+        bytes.push_data_type(DataType::Array); // type
+        bytes.skip_size(); // size
+        let start_pos = bytes.len();
+        bytes_wrapper.depth += 1;
+
+        Ok(Self {
+            depth: bytes_wrapper.depth,
+            bytes_wrapper,
+            start_pos,
+            entries: Vec::new(),
+            payload: Vec::new(),
+            offset_fixups: Vec::new(),
+            value_count: 0,
+            bytes_init_len,
+        })
+    }
+
+    #[inline]
+    fn finish(&mut self) -> BuildResult<usize> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        // Offsets are measured from `start_pos`, i.e. from the element-count field, and the payload
+        // is placed right after the entries region, so every fixed-up offset needs the element-count
+        // size plus the entries region's final size (unknown while it was still growing) added to it.
+        let payload_base = (ELEMENT_COUNT_SIZE + self.entries.len()) as u32;
+        for &(pos, provisional_offset) in &self.offset_fixups {
+            self.entries.write_offset(provisional_offset + payload_base, pos);
+        }
+
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes.try_reserve(ELEMENT_COUNT_SIZE + self.entries.len() + self.payload.len())?;
+        bytes.push_u16(self.value_count); // element-count, backfilled
+        bytes.extend_from_slice(&self.entries);
+        bytes.extend_from_slice(&self.payload);
+
+        let total_size = bytes.len() - self.start_pos;
+        bytes.write_total_size(total_size as i32, self.start_pos - ARRAY_SIZE);
+        self.bytes_wrapper.depth -= 1;
+
+        Ok(self.bytes_init_len)
+    }
+
+    #[inline]
+    fn reserve_entry(&mut self, data_type: DataType) -> usize {
+        let entry_pos = self.entries.len();
+        self.entries.skip_value_entry(1);
+        self.entries.write_data_type_by_pos(data_type, entry_pos);
+        entry_pos
+    }
+
+    // The element-count header is a `u16`, but this builder has no upfront count to check pushes
+    // against (that's the whole point of "growable"), so each push must check for itself before
+    // incrementing; otherwise a 65536th element would wrap `value_count` and write a header that
+    // no longer matches the entries/payload actually laid out in the buffer.
+    #[inline]
+    fn bump_count(&mut self) -> BuildResult<()> {
+        self.value_count = self.value_count.checked_add(1).ok_or(BuildError::TooManyElements)?;
+        Ok(())
+    }
+
+    // Bulk pushes check the whole batch against remaining `u16` capacity up front, rather than
+    // calling `bump_count` once per element: a "single reservation" bulk API that still commits
+    // entries/payload for part of the batch before failing on the last element isn't atomic.
+    #[inline]
+    fn check_additional_capacity(&self, additional: usize) -> BuildResult<()> {
+        if self.value_count as usize + additional > u16::MAX as usize {
+            return Err(BuildError::TooManyElements);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_value<F>(&mut self, data_type: DataType, f: F) -> BuildResult<()>
+    where
+        F: FnOnce(&mut Vec<u8>, u32) -> BuildResult<()>,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.bump_count()?;
+
+        let entry_pos = self.reserve_entry(data_type);
+        let offset = self.payload.len() as u32;
+        f(&mut self.payload, offset)?;
+        self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+        self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+
+        Ok(())
+    }
+
+    #[inline]
+    fn push_object(&mut self, element_count: u16, key_sorted: bool) -> BuildResult<InnerObjectBuilder<&mut Vec<u8>>> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.bump_count()?;
+        let entry_pos = self.reserve_entry(DataType::Object);
+        let offset = self.payload.len() as u32;
+        self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+        self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+
+        InnerObjectBuilder::try_new(&mut self.payload, element_count, key_sorted)
+    }
+
+    #[inline]
+    fn push_array(&mut self, element_count: u16) -> BuildResult<InnerArrayBuilder<&mut Vec<u8>>> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.bump_count()?;
+        let entry_pos = self.reserve_entry(DataType::Array);
+        let offset = self.payload.len() as u32;
+        self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+        self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+
+        InnerArrayBuilder::try_new(&mut self.payload, element_count)
+    }
+
+    #[inline]
+    fn push_packed_array(
+        &mut self,
+        element_data_type: DataType,
+        element_count: u16,
+    ) -> BuildResult<InnerPackedArrayBuilder<&mut Vec<u8>>> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.bump_count()?;
+        let entry_pos = self.reserve_entry(DataType::PackedArray);
+        let offset = self.payload.len() as u32;
+        self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+        self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+
+        InnerPackedArrayBuilder::try_new(&mut self.payload, element_data_type, element_count)
+    }
+
+    #[inline]
+    fn push_string(&mut self, value: &str) -> BuildResult<()> {
+        let size = MAX_DATA_LENGTH_SIZE + value.len();
+        let f = |payload: &mut Vec<u8>, _offset: u32| {
+            payload.try_reserve(size)?;
+            payload.push_string(value)?;
+            Ok(())
+        };
+        self.push_value(DataType::String, f)
+    }
+
+    #[inline]
+    fn push_number(&mut self, value: Number) -> BuildResult<()> {
+        let size = MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE;
+        let f = |payload: &mut Vec<u8>, _offset: u32| {
+            payload.try_reserve(size)?;
+            payload.push_number(value);
+            Ok(())
+        };
+        self.push_value(DataType::Number, f)
+    }
+
+    #[inline]
+    fn push_bool(&mut self, value: bool) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        // bool can be inlined: no payload entry, so no offset fixup is recorded.
+        self.bump_count()?;
+        let entry_pos = self.reserve_entry(DataType::Bool);
+        self.entries.write_offset(value as u32, entry_pos + DATA_TYPE_SIZE);
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        // null can be inlined: the reserved, zeroed entry slot is already correct.
+        self.bump_count()?;
+        self.reserve_entry(DataType::Null);
+        Ok(())
+    }
+
+    /// Pushes a slice of numbers in a single reservation.
+    #[inline]
+    fn push_number_slice(&mut self, values: &[Number]) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.check_additional_capacity(values.len())?;
+
+        let size = values.len() * (MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE);
+        self.payload.try_reserve(size)?;
+
+        for &value in values {
+            let entry_pos = self.reserve_entry(DataType::Number);
+            let offset = self.payload.len() as u32;
+            self.payload.push_number(value);
+            self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+            self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of strings in a single reservation.
+    #[inline]
+    fn push_string_iter<'a, I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        self.check_additional_capacity(iter.clone().count())?;
+        let size: usize = iter.clone().map(|value| MAX_DATA_LENGTH_SIZE + value.len()).sum();
+        self.payload.try_reserve(size)?;
+
+        for value in iter {
+            let entry_pos = self.reserve_entry(DataType::String);
+            let offset = self.payload.len() as u32;
+            self.payload.push_string(value)?;
+            self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+            self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes a slice of bools. Bools are inlined, so no extra reservation is needed.
+    #[inline]
+    fn push_bool_slice(&mut self, values: &[bool]) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        self.check_additional_capacity(values.len())?;
+
+        for &value in values {
+            let entry_pos = self.reserve_entry(DataType::Bool);
+            self.entries.write_offset(value as u32, entry_pos + DATA_TYPE_SIZE);
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of optional numbers in a single reservation, translating `None` into
+    /// an inlined `Null` entry and `Some` into the corresponding `Number` entry.
+    #[inline]
+    fn push_number_options<I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = Option<Number>>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        self.check_additional_capacity(iter.clone().count())?;
+        let size: usize = iter
+            .clone()
+            .map(|value| if value.is_some() { MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE } else { 0 })
+            .sum();
+        self.payload.try_reserve(size)?;
+
+        for value in iter {
+            match value {
+                Some(value) => {
+                    let entry_pos = self.reserve_entry(DataType::Number);
+                    let offset = self.payload.len() as u32;
+                    self.payload.push_number(value);
+                    self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+                    self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+                }
+                None => {
+                    self.reserve_entry(DataType::Null);
+                }
+            }
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Pushes an iterator of optional strings in a single reservation, translating `None` into
+    /// an inlined `Null` entry and `Some` into the corresponding `String` entry.
+    #[inline]
+    fn push_string_options<'a, I>(&mut self, values: I) -> BuildResult<()>
+    where
+        I: IntoIterator<Item = Option<&'a str>>,
+        I::IntoIter: Clone,
+    {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+
+        let iter = values.into_iter();
+        self.check_additional_capacity(iter.clone().count())?;
+        let size: usize = iter
+            .clone()
+            .map(|value| value.map_or(0, |value| MAX_DATA_LENGTH_SIZE + value.len()))
+            .sum();
+        self.payload.try_reserve(size)?;
+
+        for value in iter {
+            match value {
+                Some(value) => {
+                    let entry_pos = self.reserve_entry(DataType::String);
+                    let offset = self.payload.len() as u32;
+                    self.payload.push_string(value)?;
+                    self.entries.write_offset(offset, entry_pos + DATA_TYPE_SIZE);
+                    self.offset_fixups.push((entry_pos + DATA_TYPE_SIZE, offset));
+                }
+                None => {
+                    self.reserve_entry(DataType::Null);
+                }
+            }
+            self.value_count += 1;
+        }
+
+        Ok(())
+    }
+}
+
+// The width, in bytes, of one slot of a packed-array's offsets array (see `InnerPackedArrayBuilder`).
+// Ideally this would live alongside `VALUE_ENTRY_SIZE` and friends in `crate::binary`.
+const PACKED_OFFSET_SIZE: usize = 4;
+
+/// Builder for a packed, homogeneous primitive array.
+///
+/// Unlike [`InnerArrayBuilder`], which spends a whole `VALUE_ENTRY_SIZE` slot (data type + offset)
+/// on every element, a packed array stores the element `DataType` once in its header and lays the
+/// elements out contiguously: fixed-width types (`Bool`, `Number`) are written directly at
+/// `header + index * stride`, while `String` elements are addressed through an offsets array
+/// (`element_count + 1` entries, Arrow-style) followed by the concatenated UTF-8 payload. Readers
+/// distinguish this from a heterogeneous array by its `DataType::PackedArray` discriminant.
+pub(crate) struct InnerPackedArrayBuilder<B: AsMut<Vec<u8>>> {
+    bytes_wrapper: BytesWrapper<B>,
+    element_count: u16,
+    element_data_type: DataType,
+    start_pos: usize,
+    // Position where the fixed-width payload begins; unused (0) for variable-width element types.
+    payload_pos: usize,
+    // Per-element width in bytes for fixed-width element types, or 0 for variable-width ones.
+    stride: usize,
+    // Only used when `stride == 0`: each string's offset into `string_payload`, backfilled into
+    // the array's offsets region at `finish`, once the final layout is known.
+    string_offsets: Vec<u32>,
+    string_payload: Vec<u8>,
+    value_count: u16,
+    depth: usize,
+    bytes_init_len: usize,
+}
+
+impl<B: AsMut<Vec<u8>>> InnerPackedArrayBuilder<B> {
+    // `Number` has no fixed binary width, so unlike `Bool` this stride is a worst case, not a
+    // typical one: every element pays `MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE` regardless of its
+    // actual magnitude, which for small numbers can be no smaller (and sometimes larger) than the
+    // `VALUE_ENTRY_SIZE`-per-element cost a packed array exists to avoid. The win this format is
+    // after is real for `Bool`/`String` and for arrays of large or varied-magnitude numbers, but
+    // callers packing arrays of small, similarly-sized numbers should measure rather than assume.
+    #[inline]
+    fn fixed_stride(data_type: DataType) -> usize {
+        match data_type {
+            DataType::Bool => 1,
+            DataType::Number => MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE,
+            _ => 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn try_new(bytes: B, element_data_type: DataType, element_count: u16) -> BuildResult<Self> {
+        let mut bytes_wrapper = BytesWrapper::new(bytes);
+        let bytes = bytes_wrapper.bytes.as_mut();
+        let bytes_init_len = bytes.len();
+
+        let stride = Self::fixed_stride(element_data_type);
+        let payload_size = stride * element_count as usize;
+        let size = DATA_TYPE_SIZE + ARRAY_SIZE + ELEMENT_COUNT_SIZE + DATA_TYPE_SIZE + payload_size;
+        bytes.try_reserve(size)?;
+
+        bytes.push_data_type(DataType::PackedArray); // type
+        bytes.skip_size(); // size
+        let start_pos = bytes.len();
+        bytes.push_u16(element_count); // element-count
+        bytes.push_data_type(element_data_type); // element data type, stored once for all elements
+        let payload_pos = bytes.len();
+        if stride > 0 {
+            bytes.resize(payload_pos + payload_size, 0);
+        }
+        bytes_wrapper.depth += 1;
+
+        Ok(Self {
+            depth: bytes_wrapper.depth,
+            bytes_wrapper,
+            element_count,
+            element_data_type,
+            start_pos,
+            payload_pos,
+            stride,
+            string_offsets: Vec::new(),
+            string_payload: Vec::new(),
+            value_count: 0,
+            bytes_init_len,
+        })
+    }
+
+    #[inline]
+    fn check_push(&self, data_type: DataType) -> BuildResult<()> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        if self.value_count == self.element_count {
+            return Err(BuildError::InconsistentElementCount {
+                expected: self.element_count,
+                actual: self.value_count + 1,
+            });
+        }
+        if std::mem::discriminant(&self.element_data_type) != std::mem::discriminant(&data_type) {
+            return Err(BuildError::UnexpectedDataType {
+                expected: self.element_data_type,
+                actual: data_type,
+            });
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_number(&mut self, value: Number) -> BuildResult<()> {
+        self.check_push(DataType::Number)?;
+
+        let mut encoded = Vec::try_with_capacity(self.stride)?;
+        encoded.push_number(value);
+        let pos = self.payload_pos + self.value_count as usize * self.stride;
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes[pos..pos + encoded.len()].copy_from_slice(&encoded);
+
+        self.value_count += 1;
+        Ok(())
     }
 
     #[inline]
     fn push_bool(&mut self, value: bool) -> BuildResult<()> {
-        // bool can be inlined
-        let f = |bytes: &mut Vec<u8>, _offset: u32, value_entry_pos: usize| {
-            bytes.write_offset(value as u32, value_entry_pos + DATA_TYPE_SIZE);
-            Ok(())
-        };
-        self.push_value(DataType::Bool, f)
+        self.check_push(DataType::Bool)?;
+
+        let pos = self.payload_pos + self.value_count as usize;
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        bytes[pos] = value as u8;
+
+        self.value_count += 1;
+        Ok(())
     }
 
     #[inline]
-    fn push_null(&mut self) -> BuildResult<()> {
-        // null can be inlined
-        self.push_value(DataType::Null, |_, _, _| Ok(()))
+    fn push_string(&mut self, value: &str) -> BuildResult<()> {
+        self.check_push(DataType::String)?;
+
+        self.string_offsets.push(self.string_payload.len() as u32);
+        self.string_payload.try_reserve(value.len())?;
+        self.string_payload.extend_from_slice(value.as_bytes());
+
+        self.value_count += 1;
+        Ok(())
+    }
+
+    #[inline]
+    fn finish(&mut self) -> BuildResult<usize> {
+        if self.depth != self.bytes_wrapper.depth {
+            return Err(BuildError::InnerUncompletedError);
+        }
+        if self.value_count != self.element_count {
+            return Err(BuildError::InconsistentElementCount {
+                expected: self.element_count,
+                actual: self.value_count,
+            });
+        }
+
+        let bytes = self.bytes_wrapper.bytes.as_mut();
+        if self.stride == 0 {
+            // Variable-width elements: backfill the offsets array, then append the payload.
+            let offsets_size = (self.string_offsets.len() + 1) * PACKED_OFFSET_SIZE;
+            bytes.try_reserve(offsets_size + self.string_payload.len())?;
+
+            let offsets_pos = bytes.len();
+            bytes.resize(offsets_pos + offsets_size, 0);
+            for (index, &offset) in self.string_offsets.iter().enumerate() {
+                bytes.write_offset(offset, offsets_pos + index * PACKED_OFFSET_SIZE);
+            }
+            bytes.write_offset(
+                self.string_payload.len() as u32,
+                offsets_pos + self.string_offsets.len() * PACKED_OFFSET_SIZE,
+            );
+            bytes.extend_from_slice(&self.string_payload);
+        }
+
+        let total_size = bytes.len() - self.start_pos;
+        bytes.write_total_size(total_size as i32, self.start_pos - ARRAY_SIZE);
+        self.bytes_wrapper.depth -= 1;
+
+        Ok(self.bytes_init_len)
     }
 }
 
-/// Builder for encoding an array.
+/// Builder for encoding a packed, homogeneous primitive array (see [`InnerPackedArrayBuilder`]).
 #[repr(transparent)]
-pub struct ArrayBuilder(InnerArrayBuilder<Vec<u8>>);
+pub struct PackedArrayRefBuilder<'a>(pub(crate) InnerPackedArrayBuilder<&'a mut Vec<u8>>);
+
+impl<'a> PackedArrayRefBuilder<'a> {
+    /// Finishes building the packed array.
+    #[inline]
+    pub fn finish(mut self) -> BuildResult<()> {
+        self.0.finish()?;
+        Ok(())
+    }
+
+    /// Pushes a number value. The array must have been created with `DataType::Number`.
+    #[inline]
+    pub fn push_number(&mut self, value: Number) -> BuildResult<&mut Self> {
+        self.0.push_number(value)?;
+        Ok(self)
+    }
+
+    /// Pushes a bool value. The array must have been created with `DataType::Bool`.
+    #[inline]
+    pub fn push_bool(&mut self, value: bool) -> BuildResult<&mut Self> {
+        self.0.push_bool(value)?;
+        Ok(self)
+    }
+
+    /// Pushes a string value. The array must have been created with `DataType::String`.
+    #[inline]
+    pub fn push_string<Val: AsRef<str>>(&mut self, value: Val) -> BuildResult<&mut Self> {
+        self.0.push_string(value.as_ref())?;
+        Ok(self)
+    }
+}
+
+/// A single decoded element of a packed array, as read back by [`PackedArrayReader`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PackedElement<'a> {
+    Bool(bool),
+    Number(Number),
+    String(&'a str),
+}
+
+/// Read-side counterpart to [`InnerPackedArrayBuilder`]: decodes the bytes a packed array was
+/// written to back into its element `DataType` and elements, so the `DataType::PackedArray`
+/// discriminant has somewhere to land on the read path.
+///
+/// `body` must be the packed array's value bytes, i.e. everything from its `ELEMENT_COUNT_SIZE`
+/// element-count field onward (the slice a reader gets after skipping the `DataType::PackedArray`
+/// + size header that prefixes every array value, same as `InnerArrayBuilder`'s body).
+pub(crate) struct PackedArrayReader<'a> {
+    element_data_type: DataType,
+    element_count: u16,
+    payload: &'a [u8],
+}
+
+impl<'a> PackedArrayReader<'a> {
+    pub(crate) fn try_new(body: &'a [u8]) -> BuildResult<Self> {
+        if body.len() < ELEMENT_COUNT_SIZE + DATA_TYPE_SIZE {
+            return Err(BuildError::InvalidFormat);
+        }
+
+        let element_count = u16::from_be_bytes([body[0], body[1]]);
+        let element_data_type = DataType::try_from(body[ELEMENT_COUNT_SIZE])?;
+        let payload = &body[ELEMENT_COUNT_SIZE + DATA_TYPE_SIZE..];
+
+        Ok(Self { element_data_type, element_count, payload })
+    }
+
+    #[inline]
+    pub(crate) fn element_data_type(&self) -> DataType {
+        self.element_data_type
+    }
+
+    #[inline]
+    pub(crate) fn element_count(&self) -> u16 {
+        self.element_count
+    }
+
+    /// Decodes every element in order.
+    pub(crate) fn elements(&self) -> BuildResult<Vec<PackedElement<'a>>> {
+        match self.element_data_type {
+            DataType::Bool => self.read_fixed_width(1, |byte| PackedElement::Bool(byte[0] != 0)),
+            DataType::Number => {
+                let stride = MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE;
+                self.read_fixed_width(stride, |slot| PackedElement::Number(Number::from_binary(slot)))
+            }
+            DataType::String => self.read_strings(),
+            data_type => Err(BuildError::UnexpectedDataType { expected: DataType::Bool, actual: data_type }),
+        }
+    }
+
+    fn read_fixed_width(
+        &self,
+        stride: usize,
+        decode: impl Fn(&'a [u8]) -> PackedElement<'a>,
+    ) -> BuildResult<Vec<PackedElement<'a>>> {
+        let expected_len = stride * self.element_count as usize;
+        if self.payload.len() < expected_len {
+            return Err(BuildError::InvalidFormat);
+        }
+
+        Ok((0..self.element_count as usize)
+            .map(|index| decode(&self.payload[index * stride..(index + 1) * stride]))
+            .collect())
+    }
+
+    fn read_strings(&self) -> BuildResult<Vec<PackedElement<'a>>> {
+        let offsets_len = (self.element_count as usize + 1) * PACKED_OFFSET_SIZE;
+        if self.payload.len() < offsets_len {
+            return Err(BuildError::InvalidFormat);
+        }
+
+        let read_offset = |index: usize| -> u32 {
+            let pos = index * PACKED_OFFSET_SIZE;
+            u32::from_be_bytes(self.payload[pos..pos + PACKED_OFFSET_SIZE].try_into().unwrap())
+        };
+        let string_payload = &self.payload[offsets_len..];
+
+        (0..self.element_count as usize)
+            .map(|index| {
+                let start = read_offset(index) as usize;
+                let end = read_offset(index + 1) as usize;
+                let bytes = string_payload.get(start..end).ok_or(BuildError::InvalidFormat)?;
+                let value = std::str::from_utf8(bytes).map_err(|_| BuildError::InvalidFormat)?;
+                Ok(PackedElement::String(value))
+            })
+            .collect()
+    }
+}
+
+/// Builder for encoding an array.
+enum ArrayBuilderKind {
+    /// The element count is known up front: the value-entry region is reserved exactly once.
+    Fixed(InnerArrayBuilder<Vec<u8>>),
+    /// The element count is discovered while appending: see [`InnerGrowableArrayBuilder`].
+    Growable(InnerGrowableArrayBuilder<Vec<u8>>),
+}
+
+/// Builder for encoding an array.
+pub struct ArrayBuilder(ArrayBuilderKind);
 
 impl ArrayBuilder {
     /// Creates `ArrayBuilder` with specified element count.
+    ///
+    /// This is the fast path: the value-entry region is reserved once, up front, so `finish`
+    /// requires exactly `element_count` values to have been pushed.
     #[inline]
     pub fn try_new(element_count: u16) -> BuildResult<Self> {
         let bytes = Vec::try_with_capacity(DEFAULT_SIZE)?;
         let builder = InnerArrayBuilder::try_new(bytes, element_count)?;
-        Ok(Self(builder))
+        Ok(Self(ArrayBuilderKind::Fixed(builder)))
+    }
+
+    /// Creates `ArrayBuilder` without a pre-declared element count.
+    ///
+    /// Use this when the number of elements isn't known ahead of time, e.g. when building an
+    /// array from an iterator. The element count is backfilled automatically at [`finish`](Self::finish),
+    /// so there's no `element_count` to get wrong.
+    #[inline]
+    pub fn new() -> BuildResult<Self> {
+        let bytes = Vec::try_with_capacity(DEFAULT_SIZE)?;
+        let builder = InnerGrowableArrayBuilder::try_new(bytes)?;
+        Ok(Self(ArrayBuilderKind::Growable(builder)))
     }
 
     /// Finishes building the array.
     #[inline]
     pub fn finish(mut self) -> BuildResult<YasonBuf> {
-        self.0.finish()?;
-        Ok(unsafe { YasonBuf::new_unchecked(self.0.bytes_wrapper.bytes) })
+        let bytes = match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => {
+                builder.finish()?;
+                &mut builder.bytes_wrapper.bytes
+            }
+            ArrayBuilderKind::Growable(builder) => {
+                builder.finish()?;
+                &mut builder.bytes_wrapper.bytes
+            }
+        };
+        Ok(unsafe { YasonBuf::new_unchecked(std::mem::take(bytes)) })
     }
 
     /// Pushes an embedded object with specified element count and a flag which indicates whether the embedded object is sorted by key.
     #[inline]
     pub fn push_object(&mut self, element_count: u16, key_sorted: bool) -> BuildResult<ObjectRefBuilder> {
-        let obj_builder = self.0.push_object(element_count, key_sorted)?;
+        let obj_builder = match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_object(element_count, key_sorted)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_object(element_count, key_sorted)?,
+        };
         Ok(ObjectRefBuilder(obj_builder))
     }
 
     /// Pushes an embedded array with specified element count.
     #[inline]
     pub fn push_array(&mut self, element_count: u16) -> BuildResult<ArrayRefBuilder> {
-        let array_builder = self.0.push_array(element_count)?;
+        let array_builder = match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_array(element_count)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_array(element_count)?,
+        };
         Ok(ArrayRefBuilder(array_builder))
     }
 
+    /// Pushes an embedded packed, homogeneous primitive array with specified element data type and count.
+    #[inline]
+    pub fn push_packed_array(
+        &mut self,
+        element_data_type: DataType,
+        element_count: u16,
+    ) -> BuildResult<PackedArrayRefBuilder> {
+        let packed_builder = match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_packed_array(element_data_type, element_count)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_packed_array(element_data_type, element_count)?,
+        };
+        Ok(PackedArrayRefBuilder(packed_builder))
+    }
+
     /// Pushes a string value.
     #[inline]
     pub fn push_string<Val: AsRef<str>>(&mut self, value: Val) -> BuildResult<&mut Self> {
         let value = value.as_ref();
-        self.0.push_string(value)?;
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_string(value)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_string(value)?,
+        }
         Ok(self)
     }
 
     /// Pushes a number value.
     #[inline]
     pub fn push_number(&mut self, value: Number) -> BuildResult<&mut Self> {
-        self.0.push_number(value)?;
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_number(value)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_number(value)?,
+        }
         Ok(self)
     }
 
     /// Pushes a bool value.
     #[inline]
     pub fn push_bool(&mut self, value: bool) -> BuildResult<&mut Self> {
-        self.0.push_bool(value)?;
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_bool(value)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_bool(value)?,
+        }
         Ok(self)
     }
 
     /// Pushes a null value.
     #[inline]
     pub fn push_null(&mut self) -> BuildResult<&mut Self> {
-        self.0.push_null()?;
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_null()?,
+            ArrayBuilderKind::Growable(builder) => builder.push_null()?,
+        }
+        Ok(self)
+    }
+
+    /// Pushes a slice of number values, reserving space for all of them at once.
+    #[inline]
+    pub fn push_number_slice(&mut self, values: &[Number]) -> BuildResult<&mut Self> {
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_number_slice(values)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_number_slice(values)?,
+        }
+        Ok(self)
+    }
+
+    /// Pushes an iterator of string values, reserving space for all of them at once.
+    #[inline]
+    pub fn push_string_iter<'a, I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: Clone,
+    {
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_string_iter(values)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_string_iter(values)?,
+        }
+        Ok(self)
+    }
+
+    /// Pushes a slice of bool values. Bools are inlined, so no extra reservation is needed.
+    #[inline]
+    pub fn push_bool_slice(&mut self, values: &[bool]) -> BuildResult<&mut Self> {
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_bool_slice(values)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_bool_slice(values)?,
+        }
+        Ok(self)
+    }
+
+    /// Pushes an iterator of optional number values in a single reservation, translating `None`
+    /// into a `Null` entry and `Some` into a `Number` entry.
+    #[inline]
+    pub fn push_number_options<I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = Option<Number>>,
+        I::IntoIter: Clone,
+    {
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_number_options(values)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_number_options(values)?,
+        }
+        Ok(self)
+    }
+
+    /// Pushes an iterator of optional string values in a single reservation, translating `None`
+    /// into a `Null` entry and `Some` into a `String` entry.
+    #[inline]
+    pub fn push_string_options<'a, I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = Option<&'a str>>,
+        I::IntoIter: Clone,
+    {
+        match &mut self.0 {
+            ArrayBuilderKind::Fixed(builder) => builder.push_string_options(values)?,
+            ArrayBuilderKind::Growable(builder) => builder.push_string_options(values)?,
+        }
         Ok(self)
     }
 }
@@ -254,6 +1209,17 @@ impl<'a> ArrayRefBuilder<'a> {
         Ok(ArrayRefBuilder(array_builder))
     }
 
+    /// Creates an embedded packed, homogeneous primitive array with specified element data type and count.
+    #[inline]
+    pub fn push_packed_array(
+        &mut self,
+        element_data_type: DataType,
+        element_count: u16,
+    ) -> BuildResult<PackedArrayRefBuilder> {
+        let packed_builder = self.0.push_packed_array(element_data_type, element_count)?;
+        Ok(PackedArrayRefBuilder(packed_builder))
+    }
+
     /// Pushes a string value.
     #[inline]
     pub fn push_string<Val: AsRef<str>>(&mut self, value: Val) -> BuildResult<&mut Self> {
@@ -282,4 +1248,268 @@ impl<'a> ArrayRefBuilder<'a> {
         self.0.push_null()?;
         Ok(self)
     }
+
+    /// Pushes a slice of number values, reserving space for all of them at once.
+    #[inline]
+    pub fn push_number_slice(&mut self, values: &[Number]) -> BuildResult<&mut Self> {
+        self.0.push_number_slice(values)?;
+        Ok(self)
+    }
+
+    /// Pushes an iterator of string values, reserving space for all of them at once.
+    #[inline]
+    pub fn push_string_iter<'b, I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = &'b str>,
+        I::IntoIter: Clone,
+    {
+        self.0.push_string_iter(values)?;
+        Ok(self)
+    }
+
+    /// Pushes a slice of bool values. Bools are inlined, so no extra reservation is needed.
+    #[inline]
+    pub fn push_bool_slice(&mut self, values: &[bool]) -> BuildResult<&mut Self> {
+        self.0.push_bool_slice(values)?;
+        Ok(self)
+    }
+
+    /// Pushes an iterator of optional number values in a single reservation, translating `None`
+    /// into a `Null` entry and `Some` into a `Number` entry.
+    #[inline]
+    pub fn push_number_options<I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = Option<Number>>,
+        I::IntoIter: Clone,
+    {
+        self.0.push_number_options(values)?;
+        Ok(self)
+    }
+
+    /// Pushes an iterator of optional string values in a single reservation, translating `None`
+    /// into a `Null` entry and `Some` into a `String` entry.
+    #[inline]
+    pub fn push_string_options<'b, I>(&mut self, values: I) -> BuildResult<&mut Self>
+    where
+        I: IntoIterator<Item = Option<&'b str>>,
+        I::IntoIter: Clone,
+    {
+        self.0.push_string_options(values)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn growable_array_builder_counts_pushed_elements() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.push_bool(true).unwrap();
+        builder.push_null().unwrap();
+        builder.push_bool(false).unwrap();
+        assert_eq!(builder.value_count, 3);
+    }
+
+    #[test]
+    fn growable_array_builder_rejects_too_many_elements() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.value_count = u16::MAX;
+        assert!(matches!(builder.push_null(), Err(BuildError::TooManyElements)));
+        // The rejected push must not have touched the counter or reserved an entry slot.
+        assert_eq!(builder.value_count, u16::MAX);
+    }
+
+    #[test]
+    fn growable_array_builder_rejects_pushes_while_a_nested_builder_is_incomplete() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        {
+            // Dropped without calling `finish`, so `bytes_wrapper.depth` stays incremented past
+            // `builder.depth` even though the borrow on `builder` itself ends here.
+            let _nested = builder.push_array(1).unwrap();
+        }
+        assert!(matches!(builder.push_bool(true), Err(BuildError::InnerUncompletedError)));
+        assert!(matches!(builder.push_null(), Err(BuildError::InnerUncompletedError)));
+        assert!(matches!(builder.push_object(0, false), Err(BuildError::InnerUncompletedError)));
+        assert!(matches!(
+            builder.push_packed_array(DataType::Bool, 1),
+            Err(BuildError::InnerUncompletedError)
+        ));
+        assert!(matches!(builder.push_array(1), Err(BuildError::InnerUncompletedError)));
+    }
+
+    // The packed array's body starts right after the outer `[type][size]` header, at the
+    // `element_count` field: that's the slice `PackedArrayReader` expects.
+    fn packed_array_body(bytes: &[u8]) -> &[u8] {
+        &bytes[DATA_TYPE_SIZE + ARRAY_SIZE..]
+    }
+
+    #[test]
+    fn packed_array_bool_round_trips() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerPackedArrayBuilder::try_new(&mut bytes, DataType::Bool, 3).unwrap();
+        builder.push_bool(true).unwrap();
+        builder.push_bool(false).unwrap();
+        builder.push_bool(true).unwrap();
+        builder.finish().unwrap();
+
+        let reader = PackedArrayReader::try_new(packed_array_body(&bytes)).unwrap();
+        let elements = reader.elements().unwrap();
+        assert_eq!(elements.len(), 3);
+        assert!(matches!(elements[0], PackedElement::Bool(true)));
+        assert!(matches!(elements[1], PackedElement::Bool(false)));
+        assert!(matches!(elements[2], PackedElement::Bool(true)));
+    }
+
+    #[test]
+    fn packed_array_string_round_trips() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerPackedArrayBuilder::try_new(&mut bytes, DataType::String, 2).unwrap();
+        builder.push_string("hello").unwrap();
+        builder.push_string("").unwrap();
+        builder.finish().unwrap();
+
+        let reader = PackedArrayReader::try_new(packed_array_body(&bytes)).unwrap();
+        let elements = reader.elements().unwrap();
+        assert_eq!(elements.len(), 2);
+        assert!(matches!(elements[0], PackedElement::String("hello")));
+        assert!(matches!(elements[1], PackedElement::String("")));
+    }
+
+    #[test]
+    fn packed_array_reader_rejects_truncated_body() {
+        assert!(matches!(PackedArrayReader::try_new(&[0u8; 1]), Err(BuildError::InvalidFormat)));
+    }
+
+    #[test]
+    fn packed_array_number_round_trips_with_varied_magnitudes() {
+        // Every element still pays the fixed `MAX_BINARY_SIZE + NUMBER_LENGTH_SIZE` stride, so
+        // this also covers a magnitude (`"1"`) whose actual binary encoding is much shorter than
+        // that stride and must still be zero-padded and read back correctly.
+        let values: Vec<Number> =
+            ["0", "1", "-42", "123456789.5"].iter().map(|s| s.parse().unwrap()).collect();
+
+        let mut bytes = Vec::new();
+        let mut builder =
+            InnerPackedArrayBuilder::try_new(&mut bytes, DataType::Number, values.len() as u16).unwrap();
+        for &value in &values {
+            builder.push_number(value).unwrap();
+        }
+        builder.finish().unwrap();
+
+        let reader = PackedArrayReader::try_new(packed_array_body(&bytes)).unwrap();
+        let elements = reader.elements().unwrap();
+        assert_eq!(elements.len(), values.len());
+        for (element, expected) in elements.iter().zip(&values) {
+            match element {
+                PackedElement::Number(decoded) => assert_eq!(decoded.to_string(), expected.to_string()),
+                other => panic!("expected a Number element, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn push_bool_slice_counts_every_element() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerArrayBuilder::try_new(&mut bytes, 3).unwrap();
+        builder.push_bool_slice(&[true, false, true]).unwrap();
+        builder.finish().unwrap();
+        assert_eq!(builder.value_count, 3);
+    }
+
+    #[test]
+    fn push_string_iter_counts_every_element_in_one_reservation() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerArrayBuilder::try_new(&mut bytes, 2).unwrap();
+        builder.push_string_iter(["a", "bc"]).unwrap();
+        builder.finish().unwrap();
+        assert_eq!(builder.value_count, 2);
+    }
+
+    #[test]
+    fn finish_rejects_element_count_mismatch() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerArrayBuilder::try_new(&mut bytes, 3).unwrap();
+        builder.push_bool_slice(&[true, false]).unwrap();
+        assert!(matches!(
+            builder.finish(),
+            Err(BuildError::InconsistentElementCount { expected: 3, actual: 2 })
+        ));
+    }
+
+    #[test]
+    fn push_string_options_translates_none_to_null_entry() {
+        let mut bytes = Vec::new();
+        let mut builder = InnerArrayBuilder::try_new(&mut bytes, 3).unwrap();
+        builder.push_string_options([Some("a"), None, Some("c")]).unwrap();
+        // Every element, `Some` or `None`, still consumes exactly one of the pre-declared slots,
+        // so `finish` sees the count it expects instead of an off-by-one from the skipped `None`.
+        assert_eq!(builder.value_count, 3);
+        assert!(builder.finish().is_ok());
+    }
+
+    #[test]
+    fn growable_builder_push_bool_slice_counts_every_element() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.push_bool_slice(&[true, false, true]).unwrap();
+        assert_eq!(builder.value_count, 3);
+    }
+
+    #[test]
+    fn growable_builder_push_string_iter_counts_every_element() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.push_string_iter(["a", "bc", "def"]).unwrap();
+        assert_eq!(builder.value_count, 3);
+    }
+
+    #[test]
+    fn growable_builder_bulk_pushes_reject_batches_that_would_overflow_u16() {
+        // Only one slot is left below `u16::MAX`, so a 3-element batch would need a `u16::MAX + 1`st slot.
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.value_count = u16::MAX - 1;
+        assert!(matches!(
+            builder.push_bool_slice(&[true, true, true]),
+            Err(BuildError::TooManyElements)
+        ));
+        assert!(matches!(
+            builder.push_string_iter(["a", "b", "c"]),
+            Err(BuildError::TooManyElements)
+        ));
+    }
+
+    #[test]
+    fn growable_builder_bulk_pushes_are_atomic_on_overflow() {
+        // The whole batch is checked against remaining capacity before anything is written, so a
+        // batch that would overflow must leave the builder completely untouched, not partially
+        // committed up to the element that would have wrapped the counter.
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.value_count = u16::MAX - 1;
+        assert!(matches!(
+            builder.push_bool_slice(&[true, true, true]),
+            Err(BuildError::TooManyElements)
+        ));
+        assert_eq!(builder.value_count, u16::MAX - 1);
+        assert!(builder.entries.is_empty());
+    }
+
+    #[test]
+    fn growable_builder_push_string_options_counts_none_and_some() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.push_string_options([Some("a"), None, Some("c")]).unwrap();
+        assert_eq!(builder.value_count, 3);
+    }
+
+    #[test]
+    fn growable_builder_bulk_options_pushes_reject_batches_that_would_overflow_u16() {
+        let mut builder = InnerGrowableArrayBuilder::try_new(Vec::new()).unwrap();
+        builder.value_count = u16::MAX - 1;
+        assert!(matches!(
+            builder.push_string_options([Some("a"), None, Some("c")]),
+            Err(BuildError::TooManyElements)
+        ));
+        // Rejected atomically: nothing from the batch, including the leading `Some`, was written.
+        assert_eq!(builder.value_count, u16::MAX - 1);
+        assert!(builder.entries.is_empty());
+    }
 }